@@ -1,8 +1,19 @@
-use ggez::*;
-use ggez::event::{KeyCode, KeyMods};
+// `good-web-game` mirrors ggez's module layout (graphics, event, conf,
+// Context, GameResult) closely enough that most of this file doesn't need
+// to know which engine it's actually talking to. The native path stays the
+// default; the `web` feature (see Cargo.toml) swaps in the miniquad-based
+// engine for a wasm32 build. A handful of spots where the two engines still
+// diverge (color constants, key codes) are called out at their use sites.
+#[cfg(not(feature = "web"))]
+use ggez as gg;
+#[cfg(feature = "web")]
+use good_web_game as gg;
+
+use gg::*;
+use gg::event::{KeyCode, KeyMods};
 use oorandom::Rand32;
-use getrandom;
-use std::collections::LinkedList;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, LinkedList};
 use std::time::{Duration, Instant};
 
 const GRID_SIZE: (i32, i32) = (30, 20);
@@ -11,10 +22,19 @@ const SCREEN_SIZE: (f32, f32) = (
     (GRID_SIZE.0 * GRID_CELL_SIZE.0) as f32,
     (GRID_SIZE.1 * GRID_CELL_SIZE.1) as f32,
 );
-const UPDATES_PER_SECOND: f32 = 8.0;
-const TIME_PER_UPDATE: Duration = Duration::from_millis((1.0 / UPDATES_PER_SECOND * 1000.0) as u64);
-
-#[derive(Clone, Copy, PartialEq)]
+const BASE_UPDATE_MS: f32 = 125.0; // 8 updates/sec, matches the original fixed-step pace
+const MIN_UPDATE_MS: f32 = 40.0;
+const SPEED_STEP_MS: f32 = 10.0;
+const DIFFICULTY_RAMP: f32 = 0.92; // tick interval shrinks by this factor per food eaten
+
+// ggez exposes these as free constants (`graphics::WHITE`/`graphics::BLACK`);
+// good-web-game exposes them only as associated consts on `Color`. Defining
+// our own avoids sprinkling `#[cfg(feature = "web")]` through the drawing code.
+const WHITE: graphics::Color = graphics::Color { r: 1.0, g: 1.0, b: 1.0, a: 1.0 };
+const BLACK: graphics::Color = graphics::Color { r: 0.0, g: 0.0, b: 0.0, a: 1.0 };
+const LEVEL_PATH: &str = "levels/level1.txt";
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 struct GridElem {
     x: i32,
     y: i32
@@ -38,7 +58,7 @@ impl GridElem {
         }
     }
 
-    fn move_dir(&mut self, direction: Direction) -> Self {
+    fn move_dir(&self, direction: Direction) -> Self {
         match direction {
             Direction::Up => GridElem {
                 x: self.x,
@@ -59,6 +79,27 @@ impl GridElem {
         }
     }
 
+    // Like `move_dir`, but returns `None` instead of wrapping when the move
+    // would leave the grid, so callers can treat the border as a wall.
+    fn try_move_dir(&self, direction: Direction, wrap: bool) -> Option<Self> {
+        if wrap {
+            return Some(self.move_dir(direction));
+        }
+
+        let (x, y) = match direction {
+            Direction::Up => (self.x, self.y - 1),
+            Direction::Down => (self.x, self.y + 1),
+            Direction::Left => (self.x - 1, self.y),
+            Direction::Right => (self.x + 1, self.y)
+        };
+
+        if x >= 0 && x < GRID_SIZE.0 && y >= 0 && y < GRID_SIZE.1 {
+            Some(GridElem { x, y })
+        } else {
+            None
+        }
+    }
+
     fn draw(&self, ctx: &mut Context, color: graphics::Color) -> GameResult {
         let rectangle = graphics::Mesh::new_rectangle(
             ctx,
@@ -72,7 +113,7 @@ impl GridElem {
 }
 
 fn same_position(elem1: &GridElem, elem2: &GridElem) -> bool {
-    return *elem1 == *elem2
+    *elem1 == *elem2
 }
 
 #[derive(Clone, Copy, PartialEq)]
@@ -102,13 +143,131 @@ impl Direction {
             Direction::Right => Direction::Left,
         }
     }
+
+    fn all() -> [Direction; 4] {
+        [Direction::Up, Direction::Down, Direction::Left, Direction::Right]
+    }
+}
+
+// Manhattan distance between two cells. On a wrapping grid, crossing an
+// edge can be shorter than walking straight across, so each axis takes the
+// smaller of the direct and the wrapped-around distance; on a bordered
+// grid there's no wraparound to shortcut through.
+fn grid_distance(from: GridElem, to: GridElem, wrap: bool) -> i32 {
+    let dx = (from.x - to.x).abs();
+    let dy = (from.y - to.y).abs();
+    if wrap {
+        dx.min(GRID_SIZE.0 - dx) + dy.min(GRID_SIZE.1 - dy)
+    } else {
+        dx + dy
+    }
+}
+
+#[derive(PartialEq, Eq)]
+struct OpenSetEntry {
+    cost: i32,
+    elem: GridElem
+}
+
+impl Ord for OpenSetEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // BinaryHeap is a max-heap; reverse so the lowest cost comes out first.
+        other.cost.cmp(&self.cost)
+    }
+}
+
+impl PartialOrd for OpenSetEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// A* search from `start` to `goal`, treating `blocked` cells as impassable.
+// `wrap` must match the level's wraparound rule: neighbor expansion uses
+// `try_move_dir` so a non-wrapping level can't route a path across an edge
+// the snake isn't actually allowed to cross. Returns the path, start excluded.
+fn astar_path(start: GridElem, goal: GridElem, blocked: &HashSet<GridElem>, wrap: bool) -> Option<Vec<GridElem>> {
+    let mut open_set = BinaryHeap::new();
+    open_set.push(OpenSetEntry { cost: grid_distance(start, goal, wrap), elem: start });
+
+    let mut came_from: HashMap<GridElem, GridElem> = HashMap::new();
+    let mut g_score: HashMap<GridElem, i32> = HashMap::new();
+    g_score.insert(start, 0);
+
+    while let Some(OpenSetEntry { elem: current, .. }) = open_set.pop() {
+        if current == goal {
+            let mut path = vec![current];
+            let mut node = current;
+            while let Some(&prev) = came_from.get(&node) {
+                path.push(prev);
+                node = prev;
+            }
+            path.pop(); // drop `start`
+            path.reverse();
+            return Some(path);
+        }
+
+        let current_g = g_score[&current];
+        for direction in Direction::all().iter() {
+            let neighbor = match current.try_move_dir(*direction, wrap) {
+                Some(neighbor) if !blocked.contains(&neighbor) => neighbor,
+                _ => continue
+            };
+
+            let tentative_g = current_g + 1;
+            if tentative_g < *g_score.get(&neighbor).unwrap_or(&i32::MAX) {
+                came_from.insert(neighbor, current);
+                g_score.insert(neighbor, tentative_g);
+                let priority = tentative_g + grid_distance(neighbor, goal, wrap);
+                open_set.push(OpenSetEntry { cost: priority, elem: neighbor });
+            }
+        }
+    }
+
+    None
+}
+
+// Count cells reachable from `start` without crossing `blocked` or, on a
+// non-wrapping level, the board edge. Stops expanding once `limit` cells
+// have been found, since we only care about "plenty of room" vs "dead end";
+// the check runs between BFS rounds, so the count can overshoot `limit`.
+fn reachable_area(start: GridElem, blocked: &HashSet<GridElem>, wrap: bool, limit: usize) -> usize {
+    let mut visited = HashSet::new();
+    visited.insert(start);
+    let mut frontier = vec![start];
+
+    while !frontier.is_empty() && visited.len() < limit {
+        let mut next_frontier = Vec::new();
+        for elem in frontier {
+            for direction in Direction::all().iter() {
+                if let Some(neighbor) = elem.try_move_dir(*direction, wrap) {
+                    if !blocked.contains(&neighbor) && visited.insert(neighbor) {
+                        next_frontier.push(neighbor);
+                    }
+                }
+            }
+        }
+        frontier = next_frontier;
+    }
+
+    visited.len()
+}
+
+fn direction_towards(from: GridElem, to: GridElem) -> Direction {
+    for direction in Direction::all().iter() {
+        if from.move_dir(*direction) == to {
+            return *direction;
+        }
+    }
+    unreachable!("neighbors only ever differ by one move_dir step")
 }
 
 struct Snake {
     head: GridElem,
     body: LinkedList<GridElem>,
-    ate: bool,
+    ate: Option<usize>,
     self_ate: bool,
+    hit_wall: bool,
     direction: Direction
 }
 
@@ -116,7 +275,7 @@ impl Snake {
     fn new(pos: GridElem) -> Self {
         let body_pos = GridElem {
             x: pos.x - 1,
-            y: pos.y 
+            y: pos.y
         };
 
         let mut body = LinkedList::new();
@@ -124,17 +283,23 @@ impl Snake {
 
         Snake {
             head: pos,
-            body: body,
-            ate: false,
+            body,
+            ate: None,
             self_ate: false,
+            hit_wall: false,
             direction: Direction::Right
         }
     }
 
-    fn update(&mut self, food: &Food) -> GameResult<()> {
-        let new_head = self.head.move_dir(self.direction);
+    fn update(&mut self, food: &Food, level: &Level) -> GameResult<()> {
+        let moved = self.head.try_move_dir(self.direction, level.wrap);
+        self.hit_wall = match moved {
+            Some(pos) => level.walls.contains(&pos),
+            None => true
+        };
+        let new_head = moved.unwrap_or(self.head);
 
-        self.ate = same_position(&new_head, &(food.elem));
+        self.ate = food.elems.iter().position(|elem| same_position(&new_head, elem));
         self.body.push_front(self.head);
 
         // check if self ate
@@ -146,7 +311,7 @@ impl Snake {
         }
         self.self_ate = self_ate;
 
-        if !self.ate {
+        if self.ate.is_none() {
             self.body.pop_back();
         }
 
@@ -154,64 +319,265 @@ impl Snake {
         Ok(())
     }
 
+    fn occupies(&self, elem: &GridElem) -> bool {
+        same_position(&self.head, elem) || self.body.iter().any(|body_elem| same_position(body_elem, elem))
+    }
+
     fn draw(&mut self, ctx: &mut Context) -> GameResult<()>  {
         for elem in self.body.iter() {
-            elem.draw(ctx, graphics::WHITE)?;
+            elem.draw(ctx, WHITE)?;
         }
-        self.head.draw(ctx, graphics::WHITE)
+        self.head.draw(ctx, WHITE)
+    }
+
+    // Picks the next direction by pathfinding toward `food` instead of
+    // reading player input. Falls back to chasing the tail, and failing
+    // that, to whichever safe move leaves the most open space, so the
+    // snake doesn't steer itself into a pocket it can't escape.
+    fn autopilot_direction(&self, food: &GridElem, level: &Level) -> Direction {
+        let mut blocked: HashSet<GridElem> = self.body.iter().cloned().collect();
+        blocked.insert(self.head);
+        blocked.extend(level.walls.iter().cloned());
+
+        if let Some(path) = astar_path(self.head, *food, &blocked, level.wrap) {
+            if let Some(&first_step) = path.first() {
+                return direction_towards(self.head, first_step);
+            }
+        }
+
+        if let Some(&tail) = self.body.back() {
+            let mut blocked_for_tail = blocked.clone();
+            blocked_for_tail.remove(&tail);
+            if let Some(path) = astar_path(self.head, tail, &blocked_for_tail, level.wrap) {
+                if let Some(&first_step) = path.first() {
+                    return direction_towards(self.head, first_step);
+                }
+            }
+        }
+
+        Direction::all()
+            .iter()
+            .filter(|direction| direction.inverse() != self.direction)
+            .filter_map(|direction| self.head.try_move_dir(*direction, level.wrap).map(|neighbor| (*direction, neighbor)))
+            .filter(|(_, neighbor)| !blocked.contains(neighbor))
+            .max_by_key(|(_, neighbor)| reachable_area(*neighbor, &blocked, level.wrap, self.body.len() + 1))
+            .map(|(direction, _)| direction)
+            .unwrap_or(self.direction)
     }
 }
 
+const FOOD_COUNT: usize = 3;
+
 struct Food {
-    elem: GridElem
+    elems: Vec<GridElem>
 }
 
 impl Food {
     fn draw(&mut self, ctx: &mut Context) -> GameResult<()> {
-        self.elem.draw(ctx, [0.0, 255.0, 0.0, 1.0].into())
+        for elem in self.elems.iter() {
+            elem.draw(ctx, [0.0, 255.0, 0.0, 1.0].into())?;
+        }
+        Ok(())
+    }
+
+    // Rejection-samples a grid cell that isn't on the snake, a wall, or
+    // another food item. Falls back to scanning every cell once rejection
+    // sampling is unlikely to hit, and returns `None` when the board is
+    // entirely full.
+    fn spawn(rng: &mut Rand32, snake: &Snake, level: &Level, elems: &[GridElem]) -> Option<GridElem> {
+        let is_free = |candidate: &GridElem| {
+            !snake.occupies(candidate)
+                && !level.walls.contains(candidate)
+                && !elems.iter().any(|elem| same_position(elem, candidate))
+        };
+
+        for _ in 0..100 {
+            let candidate = GridElem::random(rng, GRID_SIZE.0, GRID_SIZE.1);
+            if is_free(&candidate) {
+                return Some(candidate);
+            }
+        }
+
+        (0..GRID_SIZE.0)
+            .flat_map(|x| (0..GRID_SIZE.1).map(move |y| GridElem { x, y }))
+            .find(is_free)
+    }
+
+    fn new(rng: &mut Rand32, snake: &Snake, level: &Level) -> Self {
+        let mut elems = Vec::with_capacity(FOOD_COUNT);
+        for _ in 0..FOOD_COUNT {
+            if let Some(elem) = Food::spawn(rng, snake, level, &elems) {
+                elems.push(elem);
+            }
+        }
+        Food { elems }
+    }
+}
+
+// A playable map: a set of static wall cells and whether the board edges
+// wrap around (toroidal, the original behavior) or act as a solid border.
+struct Level {
+    walls: HashSet<GridElem>,
+    wrap: bool
+}
+
+impl Level {
+    fn open() -> Self {
+        Level { walls: HashSet::new(), wrap: true }
+    }
+
+    // Parses a simple text grid (`#` = wall, anything else = empty) into a
+    // bordered, non-wrapping level. Falls back to the open toroidal level
+    // when the file can't be read, so a missing level file never crashes
+    // startup.
+    fn load(path: &str) -> Self {
+        let contents = match std::fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(_) => return Level::open()
+        };
+
+        let mut walls = HashSet::new();
+        for (y, line) in contents.lines().enumerate() {
+            for (x, cell) in line.chars().enumerate() {
+                if cell == '#' {
+                    walls.insert(GridElem { x: x as i32, y: y as i32 });
+                }
+            }
+        }
+
+        Level { walls, wrap: false }
+    }
+
+    fn draw(&self, ctx: &mut Context) -> GameResult<()> {
+        for wall in self.walls.iter() {
+            wall.draw(ctx, graphics::Color::new(0.5, 0.5, 0.5, 1.0))?;
+        }
+        Ok(())
     }
 }
 
+#[derive(Clone, Copy, PartialEq)]
+enum Phase {
+    Playing,
+    GameOver,
+    Won
+}
+
 struct State {
     snake: Snake,
     food: Food,
+    level: Level,
     rng: Rand32,
-    last_update_time: Instant
+    last_update_time: Instant,
+    autopilot: bool,
+    paused: bool,
+    base_update_ms: f32,
+    phase: Phase,
+    score: u32,
+    best_score: u32
 }
 
 impl State {
     fn new() -> State {
-        // And we seed our RNG with the system RNG.
+        // And we seed our RNG with the system RNG. The call itself doesn't
+        // need to change per target: Cargo.toml enables getrandom's "js"
+        // backend for wasm32, so this reaches the browser's crypto RNG there
+        // instead of a syscall, with no source-level branching needed.
         let mut seed: [u8; 8] = [0; 8];
         getrandom::getrandom(&mut seed).expect("Could not create RNG seed");
 
+        let level = Level::load(LEVEL_PATH);
+        let snake = Snake::new(GridElem { x: 15, y: 10 });
+        let mut rng = Rand32::new(u64::from_ne_bytes(seed));
+        let food = Food::new(&mut rng, &snake, &level);
+
         State {
-            snake: Snake::new(GridElem { x: 15, y: 10 }),
-            food: Food {
-                elem: GridElem { x: 5, y: 5 }
-            },
-            rng: Rand32::new(u64::from_ne_bytes(seed)),
-            last_update_time: Instant::now()
+            snake,
+            food,
+            level,
+            rng,
+            last_update_time: Instant::now(),
+            autopilot: false,
+            paused: false,
+            base_update_ms: BASE_UPDATE_MS,
+            phase: Phase::Playing,
+            score: 0,
+            best_score: 0
         }
     }
+
+    // How long to wait between ticks right now: the difficulty ramp shrinks
+    // the interval geometrically with every food eaten, down to a floor so
+    // the game never outruns the player's reaction time entirely.
+    fn current_update_interval(&self) -> Duration {
+        let ms = (self.base_update_ms * DIFFICULTY_RAMP.powi(self.score as i32)).max(MIN_UPDATE_MS);
+        Duration::from_millis(ms as u64)
+    }
+
+    // Puts the board back to a fresh game in place, keeping the `Context`,
+    // the RNG, the level, the speed bias and the best score around.
+    fn restart(&mut self) {
+        self.snake = Snake::new(GridElem { x: 15, y: 10 });
+        self.food = Food::new(&mut self.rng, &self.snake, &self.level);
+        self.last_update_time = Instant::now();
+        self.paused = false;
+        self.phase = Phase::Playing;
+        self.score = 0;
+    }
 }
 
-impl ggez::event::EventHandler for State {
-    fn update(&mut self, ctx: &mut Context) -> GameResult {
+// `Text::dimensions` returns a plain `(u32, u32)` on ggez but a `Rect` on
+// good-web-game; normalize to f32 width/height so callers don't care.
+#[cfg(not(feature = "web"))]
+fn text_dimensions(text: &graphics::Text, ctx: &mut Context) -> (f32, f32) {
+    let (width, height) = text.dimensions(ctx);
+    (width as f32, height as f32)
+}
+#[cfg(feature = "web")]
+fn text_dimensions(text: &graphics::Text, ctx: &mut Context) -> (f32, f32) {
+    let rect = text.dimensions(ctx);
+    (rect.w, rect.h)
+}
+
+// good-web-game's `EventHandler` is generic over the error type (to let
+// wasm targets swap in a lighter error than ggez's `GameError`); plugging
+// its own `GameError` in matches ggez's non-generic trait, so the method
+// bodies below don't need to know which engine they're running under. The
+// macro just avoids writing the (identical) impl body out twice.
+macro_rules! impl_event_handler {
+    ($handler_trait:ty) => {
+        impl $handler_trait for State {
+    fn update(&mut self, _ctx: &mut Context) -> GameResult {
+        if self.phase != Phase::Playing || self.paused {
+            return Ok(())
+        }
+
         let time_since_last_update = Instant::now() - self.last_update_time;
-        if time_since_last_update < TIME_PER_UPDATE {
+        if time_since_last_update < self.current_update_interval() {
             return Ok(())
         };
 
-        self.snake.update(&self.food)?;
+        if self.autopilot {
+            if let Some(target) = self.food.elems.iter().min_by_key(|elem| grid_distance(self.snake.head, **elem, self.level.wrap)) {
+                self.snake.direction = self.snake.autopilot_direction(target, &self.level);
+            }
+        }
+
+        self.snake.update(&self.food, &self.level)?;
+
+        if let Some(eaten_index) = self.snake.ate {
+            self.food.elems.remove(eaten_index);
+            self.score += 1;
+            self.best_score = self.best_score.max(self.score);
 
-        if self.snake.ate {
-            self.food.elem = GridElem::random(&mut self.rng, GRID_SIZE.0, GRID_SIZE.1);
+            match Food::spawn(&mut self.rng, &self.snake, &self.level, &self.food.elems) {
+                Some(elem) => self.food.elems.push(elem),
+                None => self.phase = Phase::Won
+            }
         }
 
-        if self.snake.self_ate {
-            println!("GAME OVER!");
-            event::quit(ctx);
+        if self.snake.self_ate || self.snake.hit_wall {
+            self.phase = Phase::GameOver;
         }
 
         self.last_update_time = Instant::now();
@@ -219,9 +585,34 @@ impl ggez::event::EventHandler for State {
     }
 
     fn draw(&mut self, ctx: &mut Context) -> GameResult {
-        graphics::clear(ctx, graphics::BLACK);
+        graphics::clear(ctx, BLACK);
+        self.level.draw(ctx)?;
         self.food.draw(ctx)?;
         self.snake.draw(ctx)?;
+
+        let score_text = graphics::Text::new(format!("Score: {}  Best: {}", self.score, self.best_score));
+        // A plain `[f32; 2]` converts into both ggez's and good-web-game's
+        // `mint::Point2`, so draw positions don't need to name `gg::mint`
+        // directly (good-web-game re-exports a different point/vector stack).
+        graphics::draw(ctx, &score_text, ([10.0, 10.0],))?;
+
+        let overlay = match self.phase {
+            Phase::Playing if self.paused => Some("PAUSED"),
+            Phase::Playing => None,
+            Phase::GameOver => Some("GAME OVER -- press R to restart"),
+            Phase::Won => Some("YOU WIN -- press R to restart")
+        };
+
+        if let Some(message) = overlay {
+            let overlay_text = graphics::Text::new(message);
+            let (text_width, text_height) = text_dimensions(&overlay_text, ctx);
+            let position = [
+                (SCREEN_SIZE.0 - text_width as f32) / 2.0,
+                (SCREEN_SIZE.1 - text_height as f32) / 2.0
+            ];
+            graphics::draw(ctx, &overlay_text, (position,))?;
+        }
+
         graphics::present(ctx)?;
         Ok(())
     }
@@ -233,6 +624,45 @@ impl ggez::event::EventHandler for State {
         _keymod: KeyMods,
         _repeat: bool,
     ) {
+        if self.phase != Phase::Playing {
+            if keycode == KeyCode::R {
+                self.restart();
+            }
+            return;
+        }
+
+        if keycode == KeyCode::A {
+            self.autopilot = !self.autopilot;
+            return;
+        }
+
+        if keycode == KeyCode::P {
+            self.paused = !self.paused;
+            return;
+        }
+
+        // ggez's `KeyCode` (winit's `VirtualKeyCode`) spells the "=" key
+        // `Equals`; good-web-game's (miniquad's) spells it `Equal`, with no
+        // separate `Plus` variant on either backend.
+        #[cfg(not(feature = "web"))]
+        let speed_up_key = KeyCode::Equals;
+        #[cfg(feature = "web")]
+        let speed_up_key = KeyCode::Equal;
+
+        if keycode == speed_up_key {
+            self.base_update_ms = (self.base_update_ms - SPEED_STEP_MS).max(MIN_UPDATE_MS);
+            return;
+        }
+
+        if keycode == KeyCode::Minus {
+            self.base_update_ms += SPEED_STEP_MS;
+            return;
+        }
+
+        if self.paused || self.autopilot {
+            return;
+        }
+
         if let Some(direction) = Direction::from_keycode(keycode) {
             if direction.inverse() != self.snake.direction {
                 self.snake.direction = direction;
@@ -240,7 +670,15 @@ impl ggez::event::EventHandler for State {
         }
     }
 }
+    };
+}
 
+#[cfg(not(feature = "web"))]
+impl_event_handler!(gg::event::EventHandler);
+#[cfg(feature = "web")]
+impl_event_handler!(gg::event::EventHandler<gg::GameError>);
+
+#[cfg(not(feature = "web"))]
 fn main() {
     let mut state = State::new();
 
@@ -249,6 +687,91 @@ fn main() {
         .window_mode(conf::WindowMode::default().dimensions(SCREEN_SIZE.0, SCREEN_SIZE.1))
         .build()
         .unwrap();
-    
+
     event::run(ctx, even_loop, &mut state).unwrap();
 }
+
+// good-web-game has no `ContextBuilder`/`EventsLoop` split: `start` builds
+// the Context itself and hands it to the closure that creates our State.
+#[cfg(feature = "web")]
+fn main() {
+    let conf = conf::Conf::default()
+        .window_title("Snake".to_string())
+        .window_width(SCREEN_SIZE.0 as i32)
+        .window_height(SCREEN_SIZE.1 as i32);
+
+    good_web_game::start(conf, |_ctx| Box::new(State::new())).unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid_distance_wraps_around_edges() {
+        let from = GridElem { x: 0, y: 0 };
+        let to = GridElem { x: GRID_SIZE.0 - 1, y: 0 };
+
+        assert_eq!(grid_distance(from, to, true), 1);
+        assert_eq!(grid_distance(from, to, false), GRID_SIZE.0 - 1);
+    }
+
+    #[test]
+    fn astar_path_does_not_cross_the_border_when_not_wrapping() {
+        let start = GridElem { x: 0, y: 0 };
+        let goal = GridElem { x: GRID_SIZE.0 - 1, y: 0 };
+        let blocked = HashSet::new();
+
+        let path = astar_path(start, goal, &blocked, false).expect("goal is reachable by walking across");
+        assert!(path.iter().all(|elem| elem.x >= 0 && elem.x < GRID_SIZE.0));
+        // Wrapping would reach the goal in one step; walking across takes 29.
+        assert_eq!(path.len(), (GRID_SIZE.0 - 1) as usize);
+        assert_eq!(path.last(), Some(&goal));
+    }
+
+    #[test]
+    fn astar_path_goes_around_a_wall() {
+        let start = GridElem { x: 0, y: 0 };
+        let goal = GridElem { x: 2, y: 0 };
+        let mut blocked = HashSet::new();
+        blocked.insert(GridElem { x: 1, y: 0 });
+
+        let path = astar_path(start, goal, &blocked, true).expect("goal is reachable around the wall");
+        assert!(path.iter().all(|elem| !blocked.contains(elem)));
+        assert_eq!(path.last(), Some(&goal));
+    }
+
+    #[test]
+    fn astar_path_returns_none_when_goal_is_enclosed() {
+        let start = GridElem { x: 0, y: 0 };
+        let goal = GridElem { x: 5, y: 5 };
+        let blocked: HashSet<GridElem> = [
+            GridElem { x: 4, y: 5 },
+            GridElem { x: 6, y: 5 },
+            GridElem { x: 5, y: 4 },
+            GridElem { x: 5, y: 6 },
+        ].iter().copied().collect();
+
+        assert_eq!(astar_path(start, goal, &blocked, false), None);
+    }
+
+    #[test]
+    fn reachable_area_covers_the_whole_board_when_unblocked() {
+        let blocked = HashSet::new();
+
+        let wrapping = reachable_area(GridElem { x: 0, y: 0 }, &blocked, true, usize::MAX);
+        let bordered = reachable_area(GridElem { x: 0, y: 0 }, &blocked, false, usize::MAX);
+
+        assert_eq!(wrapping, (GRID_SIZE.0 * GRID_SIZE.1) as usize);
+        assert_eq!(bordered, (GRID_SIZE.0 * GRID_SIZE.1) as usize);
+    }
+
+    #[test]
+    fn reachable_area_is_capped_by_limit() {
+        let blocked = HashSet::new();
+        // The frontier check happens between BFS rounds, not per cell, so a
+        // limit of 1 stops before the start cell's neighbors are expanded.
+        let area = reachable_area(GridElem { x: 0, y: 0 }, &blocked, true, 1);
+        assert_eq!(area, 1);
+    }
+}